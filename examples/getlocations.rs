@@ -2,8 +2,10 @@
 Edgegrid-rs example: getlocations.rs
 
 When supplied with proper credentials as environment variables, this program will
-instantiate an Authenticator and UnsignedRequest to hit the edge server locations
-API endpoint using a simple blocking Reqwest client.  Should work fine with an async client.
+instantiate an Authenticator and sign a request to hit the edge server locations
+API endpoint using a simple blocking Reqwest client, via EdgeGridSignExt. Should
+work fine with an async client too. Requires the "reqwest" and "reqwest-blocking"
+crate features.
 
 Expects the following environment variables:
 client_token
@@ -14,10 +16,9 @@ host (the akamai api endpoint hostname for the given account credentials)
 */
 
 extern crate reqwest;
-#[macro_use] extern crate edgegrid_rs;
+extern crate edgegrid_rs;
 
-use edgegrid_rs::Authenticator;
-use reqwest::header;
+use edgegrid_rs::{Authenticator, EdgeGridSignExt};
 use std::env;
 
 fn main() {
@@ -51,14 +52,13 @@ fn main() {
         Err(e) => { panic!("Error building client: {}", e); }
     };
 
-    // Generate the signed auth header
-    let signed = sign_get_request!(&authenticator, &uri);
-
-    // Send the request and auth header to akamai
+    // Sign the request and send it to akamai
     let fullurl = format!("https://{}{}", apihost, uri);
-    let result = client.get(&fullurl)
-        .header(header::AUTHORIZATION, &signed)
-        .send();
+    let signed = match client.get(&fullurl).sign_with(&authenticator) {
+        Ok(x) => x,
+        Err(e) => { panic!("Error signing request: {}", e); }
+    };
+    let result = signed.send();
     
     match result {
         Ok(x) => { println!("{}", x.text().unwrap()); },
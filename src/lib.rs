@@ -30,7 +30,15 @@ use openssl::sha::Sha256;
 use openssl::base64::encode_block;
 use time::OffsetDateTime;
 use uuid::Uuid;
-use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+#[cfg(feature = "reqwest")]
+mod reqwest_ext;
+#[cfg(feature = "reqwest")]
+pub use reqwest_ext::{EdgeGridSignExt, SignError};
 
 /*
 Akamai Edgegrid {OPEN} signing library
@@ -52,21 +60,134 @@ pub struct Authenticator {
     pub client_token: String,
     pub client_secret: String,
     pub access_token: String,
+    // Default max_body to seed RequestData::with_max_body with, e.g. loaded from
+    // an .edgerc file's "max-body" entry. Zero (the default) disables truncation.
+    pub default_max_body: usize,
 }
 
 // Data required to generate the EdgeGrid authentication header for a particular api request
 #[derive(Default)]
 pub struct RequestData {
     pub request_uri: String,
-    pub headers: HashMap<String, String>,
+    // Headers in insertion order, so the signing string is reproducible between runs
+    pub headers: Vec<(String, String)>,
+    // If set, only these headers (in this order) are included in the signature;
+    // otherwise all headers are included, sorted by (lowercased) key
+    pub signed_headers: Option<Vec<String>>,
     pub body: String,
     pub max_body: usize,
+    // If set, the base64'd sha256 of the (POST) body is attached to outgoing
+    // headers under this name, e.g. "X-Akamai-Content-SHA256"
+    pub digest_header: Option<String>,
+    // The computed base64'd sha256 of the body, filled in during signing;
+    // empty if there was no body to hash
+    pub content_digest: String,
     pub unsigned_header: String,
     pub signed_header: String
 }
 
+// Errors that can occur while verifying an inbound EdgeGrid authorization header
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerifyError {
+    // The header was not a well-formed "EG1-HMAC-SHA256 ..." string
+    MalformedHeader,
+    // The client_token or access_token in the header didn't match this Authenticator's
+    TokenMismatch,
+    // The header parsed fine but the signature doesn't match
+    BadSignature,
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VerifyError::MalformedHeader => write!(f, "malformed EdgeGrid authorization header"),
+            VerifyError::TokenMismatch => write!(f, "client_token/access_token did not match"),
+            VerifyError::BadSignature => write!(f, "signature verification failed"),
+        }
+    }
+}
+
+impl std::error::Error for VerifyError {}
+
+// Errors that can occur while loading credentials from an .edgerc file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    // The file couldn't be read; carries the underlying io::Error's message
+    Io(String),
+    // The requested [section] wasn't present in the file
+    MissingSection(String),
+    // The section was found but a required key was missing from it
+    MissingKey(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Io(msg) => write!(f, "could not read edgerc file: {}", msg),
+            ConfigError::MissingSection(s) => write!(f, "section [{}] not found in edgerc file", s),
+            ConfigError::MissingKey(k) => write!(f, "missing required key '{}' in edgerc section", k),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+// The fields pulled out of an inbound "EG1-HMAC-SHA256 ..." header
+struct ParsedAuthHeader {
+    client_token: String,
+    access_token: String,
+    timestamp: String,
+    // Everything up to (but not including) "signature=", i.e. the part that was signed
+    unsigned_header: String,
+    signature: String,
+}
+
+// Parse an "EG1-HMAC-SHA256 client_token=...;access_token=...;timestamp=...;nonce=...;signature=..."
+// header into its component fields
+fn parse_auth_header(header: &str) -> Result<ParsedAuthHeader, VerifyError> {
+    let prefix = "EG1-HMAC-SHA256 ";
+    if !header.starts_with(prefix) {
+        return Err(VerifyError::MalformedHeader);
+    }
+
+    let mut client_token = None;
+    let mut access_token = None;
+    let mut timestamp = None;
+    let mut signature = None;
+
+    for part in header[prefix.len()..].split(';') {
+        let part = part.trim();
+        if part.is_empty() {
+            continue;
+        }
+        let mut kv = part.splitn(2, '=');
+        let key = kv.next().ok_or(VerifyError::MalformedHeader)?;
+        let value = kv.next().ok_or(VerifyError::MalformedHeader)?;
+        match key {
+            "client_token" => client_token = Some(value.to_string()),
+            "access_token" => access_token = Some(value.to_string()),
+            "timestamp" => timestamp = Some(value.to_string()),
+            "signature" => signature = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    let signature = signature.ok_or(VerifyError::MalformedHeader)?;
+    let sig_marker = "signature=";
+    let sig_pos = header.rfind(sig_marker).ok_or(VerifyError::MalformedHeader)?;
+    let unsigned_header = header[..sig_pos].to_string();
+
+    Ok(ParsedAuthHeader {
+        client_token: client_token.ok_or(VerifyError::MalformedHeader)?,
+        access_token: access_token.ok_or(VerifyError::MalformedHeader)?,
+        timestamp: timestamp.ok_or(VerifyError::MalformedHeader)?,
+        unsigned_header,
+        signature,
+    })
+}
+
 impl Authenticator {
-    /* 
+    /*
         Private Static Methods
     */
 
@@ -86,31 +207,50 @@ impl Authenticator {
         encode_block(&retval).trim().to_string()
     }
 
-    // Normalize any headers to be used in the signature 
-    // Convert header keys to lowercase, trim whitespace, and join everything
-    // together into a tab-separated string
-    fn normalize_headers(headers: &HashMap<String, String>) -> String {
-        let mut retval = String::from("");
+    // Normalize the headers to be used in the signature.
+    // If `signed_headers` is given, only those headers are included, in that order;
+    // otherwise every header is included, sorted by (lowercased) key. Either way,
+    // keys are lowercased and values are trimmed before being tab-joined, so the
+    // resulting signing string is reproducible between runs.
+    fn normalize_headers(headers: &[(String, String)], signed_headers: &Option<Vec<String>>) -> String {
         let mut normed = Vec::new();
-        for (key, value) in headers.iter() {
-            normed.push(format!("{}:{}", key.to_ascii_lowercase(), value.trim()));
+        match signed_headers {
+            Some(allowed) => {
+                for name in allowed {
+                    let lname = name.to_ascii_lowercase();
+                    if let Some((_, value)) = headers.iter().find(|(k, _)| k.to_ascii_lowercase() == lname) {
+                        normed.push(format!("{}:{}", lname, value.trim()));
+                    }
+                }
+            }
+            None => {
+                let mut sorted: Vec<(String, String)> = headers
+                    .iter()
+                    .map(|(k, v)| (k.to_ascii_lowercase(), v.trim().to_string()))
+                    .collect();
+                sorted.sort_by(|a, b| a.0.cmp(&b.0));
+                for (key, value) in sorted {
+                    normed.push(format!("{}:{}", key, value));
+                }
+            }
         }
-        retval.push_str(&normed.join("\t"));
-        retval
+        normed.join("\t")
     }
 
-    // If a request body is present, ensure it is no larger than [max_body] bytes long
-    // and generate a base64'd sha256 hash of it
+    // If a request body is present, truncate it to [max_body] bytes if one was set,
+    // and generate a base64'd sha256 hash of the (possibly truncated) body. The hash
+    // is computed whenever there's a body to hash; `max` only controls truncation,
+    // so the digest doesn't silently disappear for callers who never set max_body.
     fn process_body(body: &str, max: usize, method: &str) -> (String, String) {
         // Don't bother doing anything if this isn't a POST request or there's no body
-        if method == "POST" && body.len() > 0 && max > 0 {
-            // Truncate if needed
-            if body.len() > max {
-                body.to_string().truncate(max);
-            }
-            // Make hash and return
-            let hash = Authenticator::base64_sha256(body);
-            (hash, body.to_string())
+        if method == "POST" && !body.is_empty() {
+            let truncated = if max > 0 && body.len() > max {
+                body[..max].to_string()
+            } else {
+                body.to_string()
+            };
+            let hash = Authenticator::base64_sha256(&truncated);
+            (hash, truncated)
         } else {
             ("".to_string(), "".to_string())
         }
@@ -125,14 +265,35 @@ impl Authenticator {
         Authenticator::base64_hmac_sha256(timestamp, &self.client_secret)
     }
 
+    // If the request didn't specify a max_body, fall back to this Authenticator's
+    // default_max_body (e.g. loaded from an .edgerc file's max-body entry)
+    fn apply_default_max_body(&self, request: &mut RequestData) {
+        if request.max_body == 0 {
+            request.max_body = self.default_max_body;
+        }
+    }
+
     // Create a tab-separated string with all data that will be used in signing
     fn make_data_to_sign(&self, request: &mut RequestData, method: &str) -> String {
         let (body_hash, trunc_body) = Authenticator::process_body(&request.body, request.max_body, method);
         // Replace the USRQ body with truncated body
         request.body = trunc_body;
+        // Stash the digest so callers (and post()) can get at it even if it isn't signed
+        request.content_digest = body_hash.clone();
+
+        // If requested, attach the digest as a header so it travels with the request;
+        // it'll then naturally be picked up below if it's also in signed_headers. Drop any
+        // existing header of the same name first so re-signing the same RequestData (e.g. a
+        // retry) doesn't pile up duplicate digest headers with the same name.
+        if method == "POST" && !body_hash.is_empty() {
+            if let Some(name) = request.digest_header.clone() {
+                request.headers.retain(|(k, _)| !k.eq_ignore_ascii_case(&name));
+                request.headers.push((name, body_hash.clone()));
+            }
+        }
 
         // Normalize the headers if any exist
-        let normalized = Authenticator::normalize_headers(&request.headers);
+        let normalized = Authenticator::normalize_headers(&request.headers, &request.signed_headers);
 
         // Generate string
         let data_to_sign: Vec<&str> = vec![
@@ -187,10 +348,84 @@ impl Authenticator {
         }
     }
 
+    // Load an Authenticator from the [section] of a standard Akamai .edgerc INI file,
+    // honoring host, client_token, client_secret, access_token, and an optional max-body
+    pub fn from_edgerc(path: &Path, section: &str) -> Result<Authenticator, ConfigError> {
+        let contents = fs::read_to_string(path).map_err(|e| ConfigError::Io(e.to_string()))?;
+
+        let mut in_section = false;
+        let mut section_found = false;
+        let mut host = None;
+        let mut client_token = None;
+        let mut client_secret = None;
+        let mut access_token = None;
+        let mut max_body = None;
+
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
+                continue;
+            }
+            if line.starts_with('[') && line.ends_with(']') {
+                in_section = &line[1..line.len() - 1] == section;
+                section_found = section_found || in_section;
+                continue;
+            }
+            if !in_section {
+                continue;
+            }
+            if let Some((key, value)) = line.split_once('=') {
+                let key = key.trim();
+                let value = value.trim();
+                match key {
+                    "host" => host = Some(value.to_string()),
+                    "client_token" => client_token = Some(value.to_string()),
+                    "client_secret" => client_secret = Some(value.to_string()),
+                    "access_token" => access_token = Some(value.to_string()),
+                    "max-body" => max_body = value.parse::<usize>().ok(),
+                    _ => {}
+                }
+            }
+        }
+
+        if !section_found {
+            return Err(ConfigError::MissingSection(section.to_string()));
+        }
+
+        // Strip any scheme and trailing slash Akamai tooling sometimes includes in host
+        let mut host = host.ok_or_else(|| ConfigError::MissingKey("host".to_string()))?;
+        for scheme in &["https://", "http://"] {
+            if let Some(stripped) = host.strip_prefix(scheme) {
+                host = stripped.to_string();
+            }
+        }
+        while host.ends_with('/') {
+            host.pop();
+        }
+
+        let client_token = client_token.ok_or_else(|| ConfigError::MissingKey("client_token".to_string()))?;
+        let client_secret = client_secret.ok_or_else(|| ConfigError::MissingKey("client_secret".to_string()))?;
+        let access_token = access_token.ok_or_else(|| ConfigError::MissingKey("access_token".to_string()))?;
+
+        let mut auth = Authenticator::new(&host, &client_token, &client_secret, &access_token);
+        if let Some(mb) = max_body {
+            auth.default_max_body = mb;
+        }
+        Ok(auth)
+    }
+
+    // Load an Authenticator from the "default" section of ~/.edgerc
+    pub fn from_edgerc_default() -> Result<Authenticator, ConfigError> {
+        let home = env::var("HOME").map_err(|_| ConfigError::Io("could not determine home directory".to_string()))?;
+        Authenticator::from_edgerc(&Path::new(&home).join(".edgerc"), "default")
+    }
+
     pub fn get(&self, mut request: RequestData) -> String {
         // Generate a timestamp in the format Akamai demands
         let timestamp = OffsetDateTime::now_utc().format("%Y%m%dT%H:%M:%S+0000");
 
+        self.apply_default_max_body(&mut request);
+
         // Do all the things
         self.make_auth_header(&timestamp, &mut request, "GET");
 
@@ -198,16 +433,61 @@ impl Authenticator {
         request.signed_header
     }
 
-    // Generate the Authroization header for a POST request
-    pub fn post(&self, mut request: RequestData) -> (String, String) {
+    // Generate the Authroization header for a POST request. Returns the signed header,
+    // the (possibly truncated) body, and the base64'd sha256 digest of that body, so
+    // callers using raw HTTP clients can attach the digest themselves.
+    pub fn post(&self, mut request: RequestData) -> (String, String, String) {
         // Generate timestamp
         let timestamp = OffsetDateTime::now_utc().format("%Y%m%dT%H:%M:%S+0000");
 
+        self.apply_default_max_body(&mut request);
+
         // Do all the things
         self.make_auth_header(&timestamp, &mut request, "POST");
 
         // Hand back the result
-        (request.signed_header, request.body)
+        (request.signed_header, request.body, request.content_digest)
+    }
+
+    // Verify an inbound EdgeGrid-signed GET request against this Authenticator's credentials.
+    // `request` must carry the same request_uri and headers the client signed over.
+    pub fn verify_get(&self, request: RequestData, header: &str) -> Result<(), VerifyError> {
+        self.verify(request, header, "GET")
+    }
+
+    // Verify an inbound EdgeGrid-signed POST request against this Authenticator's credentials.
+    // `request` must carry the same request_uri, headers, and body the client signed over.
+    pub fn verify_post(&self, request: RequestData, header: &str) -> Result<(), VerifyError> {
+        self.verify(request, header, "POST")
+    }
+
+    // Shared verification logic: parse the header, check the tokens match, then recompute the
+    // signature exactly as make_auth_header would have and compare it in constant time.
+    //
+    // Note this only checks that the signature is valid for the given tokens; it does not
+    // check the header's timestamp for freshness, so a captured valid header can be replayed
+    // indefinitely. Callers that need replay protection should pull the `timestamp=` field out
+    // of the raw header themselves and reject anything too old before trusting a Ok(()) result.
+    fn verify(&self, mut request: RequestData, header: &str, method: &str) -> Result<(), VerifyError> {
+        let parsed = parse_auth_header(header)?;
+
+        if parsed.client_token != self.client_token || parsed.access_token != self.access_token {
+            return Err(VerifyError::TokenMismatch);
+        }
+
+        self.apply_default_max_body(&mut request);
+        request.unsigned_header = parsed.unsigned_header;
+        let expected = self.sign_request(&mut request, method, &parsed.timestamp);
+
+        // memcmp::eq panics on slices of different lengths, so check the length first;
+        // the expected length is fixed by the HMAC, so this reveals nothing secret
+        if expected.len() == parsed.signature.len()
+            && openssl::memcmp::eq(expected.as_bytes(), parsed.signature.as_bytes())
+        {
+            Ok(())
+        } else {
+            Err(VerifyError::BadSignature)
+        }
     }
 }
 
@@ -219,11 +499,17 @@ impl RequestData {
         }
     }
 
-    pub fn with_headers(mut self, headers: HashMap<String, String>) -> Self {
+    pub fn with_headers(mut self, headers: Vec<(String, String)>) -> Self {
         self.headers = headers;
         self
     }
 
+    // Restrict the signature to this set of headers, signed in this order
+    pub fn with_signed_headers(mut self, headers: Vec<String>) -> Self {
+        self.signed_headers = Some(headers);
+        self
+    }
+
     pub fn with_body(mut self, body: &str) -> Self {
         self.body = body.to_string();
         self
@@ -233,6 +519,12 @@ impl RequestData {
         self.max_body = max;
         self
     }
+
+    // Attach the computed Content-SHA256 digest as a header with this name on POST requests
+    pub fn with_digest_header(mut self, name: &str) -> Self {
+        self.digest_header = Some(name.to_string());
+        self
+    }
 }
 
 #[macro_export]
@@ -241,7 +533,7 @@ macro_rules! sign_get_request {
     ($auther:expr, $uri:expr) => {
         $auther.get(edgegrid_rs::RequestData::new($uri))
     };
-    // Takes an Authenticator, a URI, and hashmap of headers
+    // Takes an Authenticator, a URI, and an ordered list of headers
     ($auther:expr, $uri:expr, $heads:expr) => {
         $auther.get(edgegrid_rs::RequestData::new($uri).with_headers($heads))
     };
@@ -261,8 +553,208 @@ macro_rules! sign_post_request {
     ($auther:expr, $uri:expr, $body:expr, $max:expr) => {
         $auther.post(mut RequestData::new($uri).with_body($body).with_max_body($max))
     };
-    // Takes auth, uri, body, max_body, and header hashmap
+    // Takes auth, uri, body, max_body, and an ordered list of headers
     ($auther:expr, $uri:expr, $body:expr, $max:expr, $heads:expr) => {
         $auther.post(mut RequestData::new($uri).with_body($body).with_max_body($max).with_headers($heads))
     };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_get_accepts_its_own_signature() {
+        let auth = Authenticator::new("example.com", "ctoken", "csecret", "atoken");
+
+        let signed = auth.get(RequestData::new("/some/path"));
+
+        assert_eq!(auth.verify_get(RequestData::new("/some/path"), &signed), Ok(()));
+    }
+
+    #[test]
+    fn verify_post_accepts_its_own_signature() {
+        let auth = Authenticator::new("example.com", "ctoken", "csecret", "atoken");
+
+        let (signed, body, digest) = auth.post(RequestData::new("/some/path").with_body("hello world"));
+        assert!(!digest.is_empty());
+
+        let verify_request = RequestData::new("/some/path").with_body(&body);
+        assert_eq!(auth.verify_post(verify_request, &signed), Ok(()));
+    }
+
+    #[test]
+    fn verify_post_rejects_a_tampered_body() {
+        let auth = Authenticator::new("example.com", "ctoken", "csecret", "atoken");
+
+        let (signed, _body, _digest) = auth.post(RequestData::new("/some/path").with_body("hello world"));
+
+        let verify_request = RequestData::new("/some/path").with_body("goodbye world");
+        assert_eq!(auth.verify_post(verify_request, &signed), Err(VerifyError::BadSignature));
+    }
+
+    #[test]
+    fn normalize_headers_sorts_deterministically_with_no_allow_list() {
+        let headers = vec![
+            ("X-Two".to_string(), " two ".to_string()),
+            ("A-Header".to_string(), "first".to_string()),
+            ("X-One".to_string(), "one".to_string()),
+        ];
+
+        let normalized = Authenticator::normalize_headers(&headers, &None);
+
+        assert_eq!(normalized, "a-header:first\tx-one:one\tx-two:two");
+    }
+
+    #[test]
+    fn normalize_headers_is_order_independent_with_no_allow_list() {
+        let a = vec![("A".to_string(), "1".to_string()), ("B".to_string(), "2".to_string())];
+        let b = vec![("B".to_string(), "2".to_string()), ("A".to_string(), "1".to_string())];
+
+        assert_eq!(
+            Authenticator::normalize_headers(&a, &None),
+            Authenticator::normalize_headers(&b, &None)
+        );
+    }
+
+    #[test]
+    fn normalize_headers_restricts_and_orders_via_signed_headers() {
+        let headers = vec![
+            ("X-One".to_string(), "one".to_string()),
+            ("X-Two".to_string(), "two".to_string()),
+            ("X-Three".to_string(), "three".to_string()),
+        ];
+        let signed_headers = Some(vec!["x-three".to_string(), "x-one".to_string()]);
+
+        let normalized = Authenticator::normalize_headers(&headers, &signed_headers);
+
+        assert_eq!(normalized, "x-three:three\tx-one:one");
+    }
+
+    #[test]
+    fn normalize_headers_skips_allow_listed_header_that_is_missing() {
+        let headers = vec![("X-One".to_string(), "one".to_string())];
+        let signed_headers = Some(vec!["x-one".to_string(), "x-missing".to_string()]);
+
+        let normalized = Authenticator::normalize_headers(&headers, &signed_headers);
+
+        assert_eq!(normalized, "x-one:one");
+    }
+
+    #[test]
+    fn with_signed_headers_restricts_the_signature_to_the_given_headers() {
+        let auth = Authenticator::new("example.com", "ctoken", "csecret", "atoken");
+
+        let mut unrestricted = RequestData::new("/some/path")
+            .with_headers(vec![("X-Extra".to_string(), "value".to_string())]);
+        let unrestricted_data = auth.make_data_to_sign(&mut unrestricted, "GET");
+
+        let mut restricted = RequestData::new("/some/path")
+            .with_headers(vec![("X-Extra".to_string(), "value".to_string())])
+            .with_signed_headers(vec![]);
+        let restricted_data = auth.make_data_to_sign(&mut restricted, "GET");
+
+        // Excluding X-Extra from the signed set changes the data-to-sign
+        assert_ne!(unrestricted_data, restricted_data);
+        assert!(!restricted_data.contains("x-extra"));
+    }
+
+    // Write `contents` to a uniquely-named file under the system temp dir and
+    // return its path, so from_edgerc tests don't collide with each other
+    fn write_temp_edgerc(name: &str, contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("edgegrid_rs_test_{}.edgerc", name));
+        fs::write(&path, contents).expect("failed to write temp edgerc file");
+        path
+    }
+
+    #[test]
+    fn from_edgerc_parses_the_happy_path() {
+        let path = write_temp_edgerc(
+            "happy_path",
+            "[default]\n\
+             host = https://example.com/\n\
+             client_token = ctoken\n\
+             client_secret = csecret\n\
+             access_token = atoken\n\
+             max-body = 1024\n",
+        );
+
+        let auth = Authenticator::from_edgerc(&path, "default").expect("should parse");
+
+        assert_eq!(auth.host, "example.com");
+        assert_eq!(auth.client_token, "ctoken");
+        assert_eq!(auth.client_secret, "csecret");
+        assert_eq!(auth.access_token, "atoken");
+        assert_eq!(auth.default_max_body, 1024);
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn from_edgerc_errors_on_missing_section() {
+        let path = write_temp_edgerc("missing_section", "[other]\nhost = example.com\n");
+
+        let err = match Authenticator::from_edgerc(&path, "default") {
+            Ok(_) => panic!("expected an error"),
+            Err(e) => e,
+        };
+
+        assert_eq!(err, ConfigError::MissingSection("default".to_string()));
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn from_edgerc_errors_on_missing_key() {
+        let path = write_temp_edgerc(
+            "missing_key",
+            "[default]\nhost = example.com\nclient_token = ctoken\n",
+        );
+
+        let err = match Authenticator::from_edgerc(&path, "default") {
+            Ok(_) => panic!("expected an error"),
+            Err(e) => e,
+        };
+
+        assert_eq!(err, ConfigError::MissingKey("client_secret".to_string()));
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn from_edgerc_strips_scheme_and_trailing_slashes_from_host() {
+        let path = write_temp_edgerc(
+            "host_normalization",
+            "[default]\n\
+             host = https://example.com///\n\
+             client_token = ctoken\n\
+             client_secret = csecret\n\
+             access_token = atoken\n",
+        );
+
+        let auth = Authenticator::from_edgerc(&path, "default").expect("should parse");
+
+        assert_eq!(auth.host, "example.com");
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn from_edgerc_ignores_an_unparseable_max_body() {
+        let path = write_temp_edgerc(
+            "bad_max_body",
+            "[default]\n\
+             host = example.com\n\
+             client_token = ctoken\n\
+             client_secret = csecret\n\
+             access_token = atoken\n\
+             max-body = not-a-number\n",
+        );
+
+        let auth = Authenticator::from_edgerc(&path, "default").expect("should parse");
+
+        assert_eq!(auth.default_max_body, 0);
+
+        let _ = fs::remove_file(&path);
+    }
 }
\ No newline at end of file